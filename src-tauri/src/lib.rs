@@ -1,7 +1,13 @@
+#[cfg(not(target_os = "android"))]
+use base64::prelude::*;
+#[cfg(not(target_os = "android"))]
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 #[cfg(not(target_os = "android"))]
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
+#[cfg(not(target_os = "android"))]
+use std::time::{Duration, Instant};
 
 use tauri::Manager;
 
@@ -15,14 +21,26 @@ struct SteamApp {
     user_name: String,
     steam_id: u64,
     app_id: u32,
-    /// Keeps Remote Play callback registrations alive for the app lifetime.
+    /// When Steam was initialized in `run()`, for session playtime.
+    started_at: Instant,
+    /// Connect string from a `+connect` / `+connect_lobby` launch argument, if any.
+    launch_connect: Option<String>,
+    /// Lobby id when that argument was `+connect_lobby`.
+    launch_lobby: Option<u64>,
+    /// Steam's result from the last UserStatsReceived for the local user;
+    /// `None` until it arrives.
+    stats_received: StatsOutcome,
+    /// Keeps Steam callback registrations alive for the app lifetime.
     #[allow(dead_code)]
-    _remote_play_cbs: Vec<steamworks::CallbackHandle>,
+    _callbacks: Vec<steamworks::CallbackHandle>,
 }
 
 #[cfg(not(target_os = "android"))]
 type SteamState = Mutex<Option<SteamApp>>;
 
+#[cfg(not(target_os = "android"))]
+type StatsOutcome = Arc<Mutex<Option<Result<(), String>>>>;
+
 // ─── Steam commands (desktop only) ────────────────────────────────────────────
 
 #[cfg(not(target_os = "android"))]
@@ -52,6 +70,117 @@ fn steam_get_app_id(state: tauri::State<SteamState>) -> Result<u32, String> {
         .ok_or_else(|| "Steam not initialized".to_string())
 }
 
+/// Language the player picked for this game in Steam (e.g. "german").
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_game_language(state: tauri::State<SteamState>) -> Result<String, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.apps().current_game_language())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Language of the Steam client UI itself, independent of the game setting.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_steam_ui_language(state: tauri::State<SteamState>) -> Result<String, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.utils().ui_language())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Best entry of `supported` (Steam API language names such as `"german"`)
+/// for the player: the game language first, then the Steam UI language,
+/// otherwise the first entry as the default.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_pick_best_language(state: tauri::State<SteamState>, supported: Vec<String>) -> Result<String, String> {
+    let default = supported.first().cloned().ok_or_else(|| "No supported languages given".to_string())?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let preferred = [app.client.apps().current_game_language(), app.client.utils().ui_language()];
+    Ok(preferred.iter()
+        .find_map(|lang| supported.iter().find(|s| s.eq_ignore_ascii_case(lang)))
+        .cloned()
+        .unwrap_or(default))
+}
+
+/// Seconds since Steam was initialized at startup.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_session_seconds(state: tauri::State<SteamState>) -> Result<u64, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.started_at.elapsed().as_secs())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Steamworks does not expose the Deck's per-game refresh-rate cap, so the
+/// frontend picks a Deck-appropriate frame cap from this flag instead.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_is_steam_deck(state: tauri::State<SteamState>) -> Result<bool, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.utils().is_steam_running_on_steam_deck())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Whether `app_id` is fully installed locally. False for partial installs
+/// and for titles the player only streams from another machine.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_is_app_installed(state: tauri::State<SteamState>, app_id: u32) -> Result<bool, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.apps().is_app_installed(steamworks::AppId(app_id)))
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// False when the Steam client runs in offline mode or has lost its connection.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_is_logged_on(state: tauri::State<SteamState>) -> Result<bool, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.user().logged_on())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Number of active Remote Play sessions. steamworks 0.12 has no mapping from
+/// controller handles to Remote Play guests, so seats are counted, not labelled.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_remote_play_get_session_count(state: tauri::State<SteamState>) -> Result<usize, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.remote_play().sessions().len())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Close the Steam on-screen keyboard if it is open. Returns false when no
+/// gamepad text input was showing. Not wrapped by steamworks 0.12, so this
+/// calls ISteamUtils directly while the state lock keeps the client alive.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_dismiss_gamepad_text_input(state: tauri::State<SteamState>) -> Result<bool, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    Ok(unsafe {
+        steamworks::sys::SteamAPI_ISteamUtils_DismissGamepadTextInput(steamworks::sys::SteamAPI_SteamUtils_v010())
+    })
+}
+
+/// "steam" when `Client::init` succeeded at startup, "direct" otherwise.
+/// `run()` never calls `restart_app_if_necessary`, so there is no relaunch to report.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_launch_source(state: tauri::State<SteamState>) -> Result<String, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    Ok(if guard.is_some() { "steam" } else { "direct" }.to_string())
+}
+
 #[cfg(not(target_os = "android"))]
 #[tauri::command]
 fn steam_is_dev() -> bool {
@@ -73,6 +202,24 @@ fn steam_activate_overlay(state: tauri::State<SteamState>, dialog: String) -> Re
         .ok_or_else(|| "Steam not initialized".to_string())
 }
 
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_overlay_open_chat(state: tauri::State<SteamState>, steam_id: u64) -> Result<(), String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| { s.client.friends().activate_game_overlay_to_user("chat", steamworks::SteamId::from_raw(steam_id)); })
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_overlay_open_profile(state: tauri::State<SteamState>, steam_id: u64) -> Result<(), String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| { s.client.friends().activate_game_overlay_to_user("steamid", steamworks::SteamId::from_raw(steam_id)); })
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
 #[cfg(not(target_os = "android"))]
 #[tauri::command]
 fn steam_activate_overlay_to_store(state: tauri::State<SteamState>) -> Result<(), String> {
@@ -87,18 +234,291 @@ fn steam_activate_overlay_to_store(state: tauri::State<SteamState>) -> Result<()
         .ok_or_else(|| "Steam not initialized".to_string())
 }
 
+/// Open the store page of any app (DLC, cross-sell) in the overlay.
+/// `flag` is "none", "add_to_cart" or "add_to_cart_and_show".
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_activate_to_store(state: tauri::State<SteamState>, app_id: u32, flag: String) -> Result<(), String> {
+    let flag = match flag.as_str() {
+        "none" => steamworks::OverlayToStoreFlag::None,
+        "add_to_cart" => steamworks::OverlayToStoreFlag::AddToCart,
+        "add_to_cart_and_show" => steamworks::OverlayToStoreFlag::AddToCartAndShow,
+        other => return Err(format!("Unknown store flag: {other}")),
+    };
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| { s.client.friends().activate_game_overlay_to_store(steamworks::AppId(app_id), flag); })
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_overlay_is_enabled(state: tauri::State<SteamState>) -> Result<bool, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.utils().is_overlay_enabled())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_activate_invite_dialog(state: tauri::State<SteamState>, lobby_id: u64) -> Result<(), String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    if !app.client.utils().is_overlay_enabled() {
+        return Err("Steam overlay not enabled".to_string());
+    }
+    app.client.friends().activate_invite_dialog(steamworks::LobbyId::from_raw(lobby_id));
+    Ok(())
+}
+
+/// Game a friend is currently playing, as reported by Steam.
+#[cfg(not(target_os = "android"))]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct FriendGameInfo {
+    app_id: u32,
+    lobby_id: u64,
+    /// The friend's `connect` rich presence value, if they published one.
+    connect: Option<String>,
+}
+
+/// steamworks 0.12 only wraps the write side of rich presence, so reads go
+/// through the raw ISteamFriends interface. Callers must hold the Steam state
+/// lock so the client stays alive for the duration of the call.
+#[cfg(not(target_os = "android"))]
+fn friend_rich_presence(steam_id: u64, key: &str) -> Result<String, String> {
+    let key = std::ffi::CString::new(key).map_err(|e| e.to_string())?;
+    unsafe {
+        let friends = steamworks::sys::SteamAPI_SteamFriends_v018();
+        let value = steamworks::sys::SteamAPI_ISteamFriends_GetFriendRichPresence(friends, steam_id, key.as_ptr());
+        if value.is_null() {
+            return Ok(String::new());
+        }
+        Ok(std::ffi::CStr::from_ptr(value).to_string_lossy().into_owned())
+    }
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_friend_rich_presence(state: tauri::State<SteamState>, steam_id: u64, key: String) -> Result<String, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    friend_rich_presence(steam_id, &key)
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_friend_game_played(state: tauri::State<SteamState>, steam_id: u64) -> Result<Option<FriendGameInfo>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let Some(game) = app.client.friends().get_friend(steamworks::SteamId::from_raw(steam_id)).game_played() else {
+        return Ok(None);
+    };
+    let connect = friend_rich_presence(steam_id, "connect")?;
+    Ok(Some(FriendGameInfo {
+        app_id: game.game.app_id().0,
+        lobby_id: game.lobby.raw(),
+        connect: (!connect.is_empty()).then_some(connect),
+    }))
+}
+
+/// Nickname the local user gave this friend in Steam, or `None` if unset.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_friend_nickname(state: tauri::State<SteamState>, steam_id: u64) -> Result<Option<String>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.friends().get_friend(steamworks::SteamId::from_raw(steam_id)).nick_name())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Number of friends matching `flags`, given as `FriendFlags` names separated
+/// by `|` (e.g. `"immediate"` or `"immediate|requesting_friendship"`).
+/// steamworks only wraps `get_friends`, so this goes through the raw interface
+/// to avoid building the whole list.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_friend_count(state: tauri::State<SteamState>, flags: String) -> Result<i32, String> {
+    let mut bits = steamworks::FriendFlags::NONE;
+    for name in flags.split('|').map(str::trim).filter(|n| !n.is_empty()) {
+        bits |= steamworks::FriendFlags::from_name(&name.to_uppercase())
+            .ok_or_else(|| format!("Unknown friend flag: {name}"))?;
+    }
+    if bits.is_empty() {
+        return Err("No friend flags given".to_string());
+    }
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let count = unsafe {
+        steamworks::sys::SteamAPI_ISteamFriends_GetFriendCount(
+            steamworks::sys::SteamAPI_SteamFriends_v018(),
+            bits.bits() as i32,
+        )
+    };
+    // -1 when the user is not logged on.
+    if count < 0 {
+        return Err("Steam user not logged on".to_string());
+    }
+    Ok(count)
+}
+
+/// Medium (64x64) friend avatar as raw RGBA pixels. The pixels are base64
+/// encoded: as a `Vec<u8>` they would serialize to a JSON array of 16384
+/// numbers per avatar.
+#[cfg(not(target_os = "android"))]
+#[derive(serde::Serialize)]
+struct AvatarImage {
+    width: u32,
+    height: u32,
+    rgba: String,
+}
+
+/// Avatars that Steam already has cached, keyed by Steam id. For ids left out
+/// the user information is requested from Steam, so the image starts
+/// downloading. No event reports when it is ready (steamworks 0.12 does not
+/// wrap AvatarImageLoaded), so callers ask again later for the missing ids.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_avatars(state: tauri::State<SteamState>, steam_ids: Vec<u64>) -> Result<HashMap<u64, AvatarImage>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let friends = app.client.friends();
+    Ok(steam_ids
+        .into_iter()
+        .filter_map(|id| {
+            let steam_id = steamworks::SteamId::from_raw(id);
+            let Some(rgba) = friends.get_friend(steam_id).medium_avatar() else {
+                friends.request_user_information(steam_id, false);
+                return None;
+            };
+            Some((id, AvatarImage { width: 64, height: 64, rgba: BASE64_STANDARD.encode(rgba) }))
+        })
+        .collect())
+}
+
+/// Finds the join argument Steam passes when the game is launched from a
+/// friend's "Join Game" or an accepted invite: `+connect <value>` or
+/// `+connect_lobby <lobby id>`. Returns the flag and its value. Used for both
+/// argv and the `steam://run` command line.
+#[cfg(not(target_os = "android"))]
+fn parse_connect_arg<'a>(tokens: impl IntoIterator<Item = &'a str>) -> Option<(&'a str, &'a str)> {
+    let mut tokens = tokens.into_iter();
+    while let Some(flag) = tokens.next() {
+        if flag == "+connect" || flag == "+connect_lobby" {
+            return tokens.next().map(|value| (flag, value));
+        }
+    }
+    None
+}
+
+#[cfg(not(target_os = "android"))]
+fn connect_lobby_id((flag, value): (&str, &str)) -> Option<u64> {
+    (flag == "+connect_lobby").then(|| value.parse().ok()).flatten()
+}
+
+/// Connect string the game was launched with; live join requests arrive as
+/// `steam://join-requested` events instead.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_launch_connect(state: tauri::State<SteamState>) -> Result<Option<String>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.launch_connect.clone())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Raw command line of a `steam://run` deep-link launch; empty for normal launches.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_launch_command_line(state: tauri::State<SteamState>) -> Result<String, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.apps().launch_command_line())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Lobby to join straight away when the game was launched from a lobby invite.
+/// A `steam://run` deep link carries it in the launch command line; an invite
+/// that cold-starts the game passes it in argv instead.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_launch_connect_lobby(state: tauri::State<SteamState>) -> Result<Option<u64>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| {
+            parse_connect_arg(s.client.apps().launch_command_line().split_whitespace())
+                .and_then(connect_lobby_id)
+                .or(s.launch_lobby)
+        })
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// steamworks turns names and values into C strings with `CString::new(..).unwrap()`,
+/// so an embedded NUL from JS would panic while the state lock is held.
+#[cfg(not(target_os = "android"))]
+fn reject_nul(value: &str, what: &str) -> Result<(), String> {
+    if value.contains('\0') {
+        return Err(format!("{what} must not contain NUL characters"));
+    }
+    Ok(())
+}
+
+/// Steam rejects rich presence values of 256 bytes or more (the limit includes the NUL).
+#[cfg(not(target_os = "android"))]
+const MAX_RICH_PRESENCE_VALUE_LEN: usize = 255;
+
+/// Publish (or clear with `None`) the `connect` rich presence key that Steam
+/// hands to friends who press "Join Game".
 #[cfg(not(target_os = "android"))]
 #[tauri::command]
-fn steam_unlock_achievement(state: tauri::State<SteamState>, achievement_id: String) -> Result<(), String> {
+fn steam_set_connect_string(state: tauri::State<SteamState>, value: Option<String>) -> Result<(), String> {
+    if let Some(v) = &value {
+        if v.len() > MAX_RICH_PRESENCE_VALUE_LEN {
+            return Err(format!("connect string exceeds {MAX_RICH_PRESENCE_VALUE_LEN} bytes"));
+        }
+        reject_nul(v, "connect string")?;
+    }
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    if app.client.friends().set_rich_presence("connect", value.as_deref()) {
+        Ok(())
+    } else {
+        Err("Steam SetRichPresence failed".to_string())
+    }
+}
+
+/// Steam shows the unlock toast when the stats are stored, not when the
+/// achievement is set. With `suppress_notification` the unlock is only set
+/// locally; call `steam_store_stats` later to flush (and toast) a batch.
+/// `steam_set_stat`, `steam_increment_stat_*` and the other unlock/clear
+/// commands store immediately, so any of them also shows pending toasts.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_unlock_achievement(
+    state: tauri::State<SteamState>,
+    achievement_id: String,
+    suppress_notification: Option<bool>,
+) -> Result<(), String> {
     let guard = state.lock().map_err(|e| e.to_string())?;
     guard.as_ref()
         .map(|s| {
             s.client.user_stats().achievement(&achievement_id).set().ok();
-            s.client.user_stats().store_stats().ok();
+            if !suppress_notification.unwrap_or(false) {
+                s.client.user_stats().store_stats().ok();
+            }
         })
         .ok_or_else(|| "Steam not initialized".to_string())
 }
 
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_store_stats(state: tauri::State<SteamState>) -> Result<(), String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    app.client.user_stats().store_stats().map_err(|_| "Steam store_stats failed".to_string())
+}
+
 #[cfg(not(target_os = "android"))]
 #[tauri::command]
 fn open_devtools(window: tauri::WebviewWindow) {
@@ -139,6 +559,61 @@ fn steam_get_unlocked_achievements(state: tauri::State<SteamState>, ids: Vec<Str
         .ok_or_else(|| "Steam not initialized".to_string())
 }
 
+/// All achievement api names defined for the app. Steam reports zero
+/// achievements until the local user's stats have arrived, so that case is a
+/// retriable error rather than an empty list.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_achievement_names(state: tauri::State<SteamState>) -> Result<Vec<String>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let stats = app.client.user_stats();
+    // get_achievement_names() panics when the count is zero, so check first.
+    stats
+        .get_num_achievements()
+        .map_err(|_| "Steam achievements not available yet, retry after stats are received".to_string())?;
+    stats
+        .get_achievement_names()
+        .ok_or_else(|| "Steam achievement names unavailable".to_string())
+}
+
+/// Steam-localized title and description of an achievement.
+#[cfg(not(target_os = "android"))]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct AchievementDisplayInfo {
+    display_name: String,
+    description: String,
+    hidden: bool,
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_achievement_display_info(state: tauri::State<SteamState>, name: String) -> Result<AchievementDisplayInfo, String> {
+    reject_nul(&name, "achievement name")?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let stats = app.client.user_stats();
+    if !matches!(*app.stats_received.lock().map_err(|e| e.to_string())?, Some(Ok(()))) {
+        return Err("Steam stats not available yet, retry after stats are received".to_string());
+    }
+    // Steam returns empty display attributes for unknown names, so look the
+    // achievement itself up first.
+    let achievement = stats.achievement(&name);
+    achievement.get().map_err(|_| format!("Unknown achievement: {name}"))?;
+    let attribute = |key: &str| {
+        achievement
+            .get_achievement_display_attribute(key)
+            .map(str::to_string)
+            .map_err(|_| format!("Invalid display attribute {key} for achievement {name}"))
+    };
+    Ok(AchievementDisplayInfo {
+        display_name: attribute("name")?,
+        description: attribute("desc")?,
+        hidden: attribute("hidden")? == "1",
+    })
+}
+
 #[cfg(not(target_os = "android"))]
 #[tauri::command]
 fn steam_set_stat(state: tauri::State<SteamState>, stat_name: String, value: i32) -> Result<(), String> {
@@ -151,6 +626,37 @@ fn steam_set_stat(state: tauri::State<SteamState>, stat_name: String, value: i32
         .ok_or_else(|| "Steam not initialized".to_string())
 }
 
+/// Add `delta` to an int stat and store it, all under the state lock so a
+/// concurrent stat refresh cannot interleave. Returns the new value.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_increment_stat_int(state: tauri::State<SteamState>, name: String, delta: i32) -> Result<i32, String> {
+    reject_nul(&name, "stat name")?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let stats = app.client.user_stats();
+    let current = stats.get_stat_i32(&name).map_err(|_| format!("Unknown Steam stat: {name}"))?;
+    let value = current.saturating_add(delta);
+    stats.set_stat_i32(&name, value).map_err(|_| format!("Steam SetStat failed for {name}"))?;
+    stats.store_stats().ok();
+    Ok(value)
+}
+
+/// Float counterpart of `steam_increment_stat_int`.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_increment_stat_float(state: tauri::State<SteamState>, name: String, delta: f32) -> Result<f32, String> {
+    reject_nul(&name, "stat name")?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let stats = app.client.user_stats();
+    let current = stats.get_stat_f32(&name).map_err(|_| format!("Unknown Steam stat: {name}"))?;
+    let value = current + delta;
+    stats.set_stat_f32(&name, value).map_err(|_| format!("Steam SetStat failed for {name}"))?;
+    stats.store_stats().ok();
+    Ok(value)
+}
+
 #[cfg(not(target_os = "android"))]
 #[tauri::command]
 fn steam_clear_achievement(state: tauri::State<SteamState>, achievement_id: String) -> Result<(), String> {
@@ -179,6 +685,427 @@ fn steam_reset_all_stats(state: tauri::State<SteamState>, achievements_too: bool
     Ok(())
 }
 
+#[cfg(not(target_os = "android"))]
+const STATS_WAIT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Resolves once Steam has delivered the local user's stats, asking for them
+/// again if they have not arrived yet. Fails with Steam's error as soon as the
+/// request fails (e.g. offline), or after `STATS_WAIT_TIMEOUT`.
+#[cfg(not(target_os = "android"))]
+async fn wait_for_stats(state: &SteamState) -> Result<(), String> {
+    let received = {
+        let guard = state.lock().map_err(|e| e.to_string())?;
+        let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+        let mut outcome = app.stats_received.lock().map_err(|e| e.to_string())?;
+        if matches!(*outcome, Some(Ok(()))) {
+            return Ok(());
+        }
+        // Forget an earlier failure so the wait below sees this request's result.
+        *outcome = None;
+        drop(outcome);
+        app.client.user_stats().request_user_stats(app.steam_id);
+        app.stats_received.clone()
+    };
+    tauri::async_runtime::spawn_blocking(move || {
+        let deadline = Instant::now() + STATS_WAIT_TIMEOUT;
+        loop {
+            if let Some(result) = received.lock().map_err(|e| e.to_string())?.clone() {
+                return result.map_err(|e| format!("Steam stats request failed: {e}"));
+            }
+            if Instant::now() >= deadline {
+                return Err("Timed out waiting for Steam stats".to_string());
+            }
+            std::thread::sleep(Duration::from_millis(50));
+        }
+    })
+    .await
+    .map_err(|e| e.to_string())?
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+async fn steam_request_current_stats(state: tauri::State<'_, SteamState>) -> Result<(), String> {
+    wait_for_stats(&state).await
+}
+
+/// Achievement flags and stat values for the profile screen, keyed by api name.
+/// Names Steam does not know are left out.
+#[cfg(not(target_os = "android"))]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct StatsSnapshot {
+    achievements: HashMap<String, bool>,
+    int_stats: HashMap<String, i32>,
+    float_stats: HashMap<String, f32>,
+}
+
+/// Read many achievements and stats in one IPC call. Every read fails until
+/// the local user's stats have arrived, so this first waits for them like
+/// `steam_request_current_stats` and only errors if that wait fails.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+async fn steam_get_stats_snapshot(
+    state: tauri::State<'_, SteamState>,
+    achievements: Vec<String>,
+    int_stats: Vec<String>,
+    float_stats: Vec<String>,
+) -> Result<StatsSnapshot, String> {
+    for name in achievements.iter().chain(&int_stats).chain(&float_stats) {
+        reject_nul(name, "achievement or stat name")?;
+    }
+    wait_for_stats(&state).await?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let stats = app.client.user_stats();
+    Ok(StatsSnapshot {
+        achievements: achievements.into_iter()
+            .filter_map(|id| stats.achievement(&id).get().ok().map(|v| (id, v)))
+            .collect(),
+        int_stats: int_stats.into_iter()
+            .filter_map(|name| stats.get_stat_i32(&name).ok().map(|v| (name, v)))
+            .collect(),
+        float_stats: float_stats.into_iter()
+            .filter_map(|name| stats.get_stat_f32(&name).ok().map(|v| (name, v)))
+            .collect(),
+    })
+}
+
+/// Steam Datagram Relay readiness, each availability field one of "current",
+/// "attempting", "waiting", "never_tried" or a failure state ("failed",
+/// "retrying", ...). The nearest relay fields stay `None` until Steam has
+/// measured pings.
+#[cfg(not(target_os = "android"))]
+#[derive(serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+struct RelayStatus {
+    availability: String,
+    network_config: String,
+    any_relay: String,
+    ping_measurement_in_progress: bool,
+    debug_message: String,
+    /// Code of the closest relay data center, e.g. "fra".
+    nearest_relay: Option<String>,
+    nearest_relay_ping_ms: Option<i32>,
+}
+
+/// Data center code packed into a `SteamNetworkingPOPID`: three characters in
+/// the low 24 bits, an optional fourth in the top byte.
+#[cfg(not(target_os = "android"))]
+fn pop_code(id: u32) -> String {
+    [id >> 16, id >> 8, id, id >> 24]
+        .into_iter()
+        .map(|b| (b & 0xff) as u8)
+        .filter(|&b| b != 0)
+        .map(char::from)
+        .collect()
+}
+
+/// Closest relay data center and the direct ping to it. steamworks 0.12 does
+/// not wrap the POP queries, so this uses ISteamNetworkingUtils directly;
+/// callers must hold the Steam state lock.
+#[cfg(not(target_os = "android"))]
+fn nearest_relay_ping() -> Option<(String, i32)> {
+    use steamworks::sys;
+    unsafe {
+        let utils = sys::SteamAPI_SteamNetworkingUtils_SteamAPI_v004();
+        let mut location: sys::SteamNetworkPingLocation_t = std::mem::zeroed();
+        if sys::SteamAPI_ISteamNetworkingUtils_GetLocalPingLocation(utils, &mut location) < 0.0 {
+            return None;
+        }
+        let count = sys::SteamAPI_ISteamNetworkingUtils_GetPOPCount(utils);
+        let mut pops: Vec<sys::SteamNetworkingPOPID> = vec![0; count.max(0) as usize];
+        let listed = sys::SteamAPI_ISteamNetworkingUtils_GetPOPList(utils, pops.as_mut_ptr(), count);
+        pops.truncate(listed.max(0) as usize);
+        pops.into_iter()
+            .map(|pop| (pop, sys::SteamAPI_ISteamNetworkingUtils_GetDirectPingToPOP(utils, pop)))
+            .filter(|&(_, ping)| ping >= 0)
+            .min_by_key(|&(_, ping)| ping)
+            .map(|(pop, ping)| (pop_code(pop), ping))
+    }
+}
+
+#[cfg(not(target_os = "android"))]
+fn availability_label(result: steamworks::networking_types::NetworkingAvailabilityResult) -> String {
+    use steamworks::networking_types::{NetworkingAvailability as A, NetworkingAvailabilityError as E};
+    match result {
+        Ok(A::NeverTried) => "never_tried",
+        Ok(A::Waiting) => "waiting",
+        Ok(A::Attempting) => "attempting",
+        Ok(A::Current) => "current",
+        Err(E::Unknown) => "unknown",
+        Err(E::CannotTry) => "cannot_try",
+        Err(E::Failed) => "failed",
+        Err(E::Previously) => "previously",
+        Err(E::Retrying) => "retrying",
+    }
+    .to_string()
+}
+
+/// Start connecting to the relay network ahead of matchmaking; poll
+/// `steam_net_get_relay_status` until `availability` is "current".
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_net_init_relay_access(state: tauri::State<SteamState>) -> Result<(), String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.networking_utils().init_relay_network_access())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_net_get_relay_status(state: tauri::State<SteamState>) -> Result<RelayStatus, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let status = app.client.networking_utils().detailed_relay_network_status();
+    let nearest = nearest_relay_ping();
+    Ok(RelayStatus {
+        availability: availability_label(status.availability()),
+        network_config: availability_label(status.network_config()),
+        any_relay: availability_label(status.any_relay()),
+        ping_measurement_in_progress: status.is_ping_measurement_in_progress(),
+        debug_message: status.debugging_message().to_string(),
+        nearest_relay_ping_ms: nearest.as_ref().map(|(_, ping)| *ping),
+        nearest_relay: nearest.map(|(pop, _)| pop),
+    })
+}
+
+/// Root of the running Steam client installation.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_steam_install_path(state: tauri::State<SteamState>) -> Result<String, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let path = unsafe {
+        let ptr = steamworks::sys::SteamAPI_GetSteamInstallPath();
+        if ptr.is_null() {
+            String::new()
+        } else {
+            std::ffi::CStr::from_ptr(ptr).to_string_lossy().into_owned()
+        }
+    };
+    if path.is_empty() {
+        return Err("Steam install path could not be determined".to_string());
+    }
+    Ok(path)
+}
+
+/// Library folder paths listed in `libraryfolders.vdf`. Handles both the
+/// current layout (`"path"` inside numbered blocks, next to an `"apps"` block
+/// of app id / size pairs) and the legacy one (numbered keys with the path as
+/// value), looking only at entries directly under the root block.
+#[cfg(not(target_os = "android"))]
+fn library_folder_paths(src: &str) -> Vec<String> {
+    use VdfTok as Tok;
+    let mut toks = vdf_tokens(src).into_iter().peekable();
+    let mut paths = Vec::new();
+    let mut depth = 0usize;
+    let mut in_folder = false;
+    while let Some(tok) = toks.next() {
+        match tok {
+            Tok::Open => depth += 1,
+            Tok::Close => {
+                depth = depth.saturating_sub(1);
+                if depth <= 1 {
+                    in_folder = false;
+                }
+            }
+            Tok::Str(key) => {
+                let numbered = key.parse::<u32>().is_ok();
+                match toks.peek() {
+                    Some(Tok::Open) if depth == 1 => in_folder = numbered,
+                    Some(&Tok::Str(value)) => {
+                        if (depth == 1 && numbered) || (depth == 2 && in_folder && key == "path") {
+                            paths.push(value.replace("\\\\", "\\"));
+                        }
+                        toks.next();
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+    paths
+}
+
+/// Steam library folders, read from `steamapps/libraryfolders.vdf` under the
+/// Steam install path since steamworks does not expose them.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_library_folders(state: tauri::State<SteamState>) -> Result<Vec<String>, String> {
+    let root = steam_get_steam_install_path(state)?;
+    let path = std::path::Path::new(&root).join("steamapps").join("libraryfolders.vdf");
+    let src = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Steam library folders not readable at {}: {e}", path.display()))?;
+    let folders = library_folder_paths(&src);
+    if folders.is_empty() {
+        return Err(format!("No library folders listed in {}", path.display()));
+    }
+    Ok(folders)
+}
+
+// ─── Steam Input manifest (desktop only) ──────────────────────────────────────
+
+/// Copied next to the executable by the `tauri:build:*` scripts.
+#[cfg(not(target_os = "android"))]
+const INPUT_MANIFEST_FILENAME: &str = "game_actions_X.vdf";
+
+#[cfg(not(target_os = "android"))]
+enum VdfTok<'a> { Str(&'a str), Open, Close }
+
+/// Split a Valve KeyValues (VDF) file into tokens. Only the subset Steam's own
+/// files use is understood: quoted or bare strings, braces and `//` comments.
+/// Escapes inside quoted strings are left as written.
+#[cfg(not(target_os = "android"))]
+fn vdf_tokens(src: &str) -> Vec<VdfTok<'_>> {
+    use VdfTok as Tok;
+    let mut toks = Vec::new();
+    let mut rest = src.trim_start();
+    while !rest.is_empty() {
+        if let Some(r) = rest.strip_prefix("//") {
+            rest = r.split_once('\n').map_or("", |(_, r)| r);
+        } else if let Some(r) = rest.strip_prefix('{') {
+            toks.push(Tok::Open);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('}') {
+            toks.push(Tok::Close);
+            rest = r;
+        } else if let Some(r) = rest.strip_prefix('"') {
+            let (s, r) = r.split_once('"').unwrap_or((r, ""));
+            toks.push(Tok::Str(s));
+            rest = r;
+        } else {
+            let end = rest
+                .find(|c: char| c.is_whitespace() || matches!(c, '{' | '}' | '"'))
+                .unwrap_or(rest.len());
+            toks.push(Tok::Str(&rest[..end]));
+            rest = &rest[end..];
+        }
+        rest = rest.trim_start();
+    }
+    toks
+}
+
+/// Names of the action sets under the top-level "actions" block of a Steam
+/// Input manifest.
+#[cfg(not(target_os = "android"))]
+fn manifest_action_sets(src: &str) -> Vec<String> {
+    use VdfTok as Tok;
+    let toks = vdf_tokens(src);
+    let mut names = Vec::new();
+    let mut depth = 0usize;
+    let mut in_actions = false;
+    for (i, tok) in toks.iter().enumerate() {
+        match tok {
+            Tok::Open => depth += 1,
+            Tok::Close => {
+                depth = depth.saturating_sub(1);
+                if depth <= 1 {
+                    in_actions = false;
+                }
+            }
+            Tok::Str(key) if matches!(toks.get(i + 1), Some(Tok::Open)) => {
+                if depth == 1 && *key == "actions" {
+                    in_actions = true;
+                } else if depth == 2 && in_actions {
+                    names.push(key.to_string());
+                }
+            }
+            Tok::Str(_) => {}
+        }
+    }
+    names
+}
+
+/// Action sets defined in the shipped Steam Input manifest. steamworks cannot
+/// enumerate action sets, so the VDF next to the executable is parsed instead.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_input_list_action_sets() -> Result<Vec<String>, String> {
+    let path = std::env::current_exe()
+        .map_err(|e| format!("current_exe error: {e}"))?
+        .with_file_name(INPUT_MANIFEST_FILENAME);
+    let src = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Steam Input manifest not readable at {}: {e}", path.display()))?;
+    Ok(manifest_action_sets(&src))
+}
+
+// ─── Steam Cloud settings (desktop only) ──────────────────────────────────────
+
+#[cfg(not(target_os = "android"))]
+const CLOUD_SETTINGS_FILENAME: &str = "diception_settings.json";
+
+/// Load the settings map from Steam Cloud. A missing file is an empty map;
+/// a corrupt one is an error so `steam_settings_set` cannot overwrite it.
+#[cfg(not(target_os = "android"))]
+fn read_cloud_settings(client: &steamworks::Client) -> Result<HashMap<String, String>, String> {
+    use std::io::Read;
+    let file = client.remote_storage().file(CLOUD_SETTINGS_FILENAME);
+    if !file.exists() {
+        return Ok(HashMap::new());
+    }
+    let mut data = String::new();
+    file.read()
+        .read_to_string(&mut data)
+        .map_err(|e| format!("cloud read error: {e}"))?;
+    serde_json::from_str(&data).map_err(|e| format!("cloud settings parse error: {e}"))
+}
+
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_settings_get(state: tauri::State<SteamState>, key: String) -> Result<Option<String>, String> {
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    Ok(read_cloud_settings(&app.client)?.remove(&key))
+}
+
+/// Read-modify-write of the whole settings file. Holding the state lock for
+/// the full cycle serializes concurrent writers within the process.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_settings_set(state: tauri::State<SteamState>, key: String, value: String) -> Result<(), String> {
+    use std::io::Write;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    let app = guard.as_ref().ok_or_else(|| "Steam not initialized".to_string())?;
+    let mut settings = read_cloud_settings(&app.client)?;
+    settings.insert(key, value);
+    let data = serde_json::to_vec(&settings).map_err(|e| e.to_string())?;
+    app.client
+        .remote_storage()
+        .file(CLOUD_SETTINGS_FILENAME)
+        .write()
+        .write_all(&data)
+        .map_err(|e| format!("cloud write error: {e}"))
+}
+
+/// True once Steam has uploaded the file to the cloud, as opposed to only
+/// having written it locally.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_cloud_file_persisted(state: tauri::State<SteamState>, file_name: String) -> Result<bool, String> {
+    reject_nul(&file_name, "file name")?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| s.client.remote_storage().file(&file_name).is_persisted())
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
+/// Platforms a cloud file syncs to, e.g. `["windows", "macos", "linux"]`.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_cloud_get_sync_platforms(state: tauri::State<SteamState>, file_name: String) -> Result<Vec<String>, String> {
+    reject_nul(&file_name, "file name")?;
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    guard.as_ref()
+        .map(|s| {
+            s.client.remote_storage().file(&file_name).get_sync_platforms()
+                .iter_names()
+                .map(|(name, _)| name.to_lowercase())
+                .collect()
+        })
+        .ok_or_else(|| "Steam not initialized".to_string())
+}
+
 // ─── Storage helpers (all platforms) ─────────────────────────────────────────
 
 const SAVE_FILENAME: &str = "diception_save.sav";
@@ -232,31 +1159,81 @@ fn android_is_dev() -> bool {
 
 // ─── JS init scripts ──────────────────────────────────────────────────────────
 
+/// `__STEAM_BRIDGE_LABELS__` is replaced by `steam_init_script` with the
+/// webview labels that may see `window.steam`.
 #[cfg(not(target_os = "android"))]
 const STEAM_INIT_SCRIPT: &str = r#"
 (function() {
     var ipc = window.__TAURI_INTERNALS__;
     if (!ipc) return;
+    var webview = ipc.metadata && ipc.metadata.currentWebview;
+    if (__STEAM_BRIDGE_LABELS__.indexOf(webview && webview.label) === -1) return;
     window.steam = {
         getUserName:      function()         { return ipc.invoke('steam_get_user_name'); },
         getSteamId:       function()         { return ipc.invoke('steam_get_steam_id'); },
         getAppId:         function()         { return ipc.invoke('steam_get_app_id'); },
+        getGameLanguage:  function()         { return ipc.invoke('steam_get_game_language'); },
+        getSteamUiLanguage: function()       { return ipc.invoke('steam_get_steam_ui_language'); },
+        pickBestLanguage:   function(supported) { return ipc.invoke('steam_pick_best_language', { supported: supported }); },
+        getSessionSeconds: function()        { return ipc.invoke('steam_get_session_seconds'); },
+        isSteamDeck:      function()         { return ipc.invoke('steam_is_steam_deck'); },
+        isAppInstalled:   function(appId)    { return ipc.invoke('steam_is_app_installed', { appId: appId }); },
+        isLoggedOn:       function()         { return ipc.invoke('steam_is_logged_on'); },
+        getRemotePlaySessionCount: function() { return ipc.invoke('steam_remote_play_get_session_count'); },
+        dismissGamepadTextInput: function()  { return ipc.invoke('steam_dismiss_gamepad_text_input'); },
         isDev:            function()         { return ipc.invoke('steam_is_dev'); },
+        reloadBridge:     function()         { return ipc.invoke('steam_reload_bridge_script'); },
         quit:             function()         { return ipc.invoke('steam_quit'); },
         activateOverlay:  function(dialog)   { return ipc.invoke('steam_activate_overlay', { dialog: dialog || 'Friends' }); },
+        openChat:         function(steamId)  { return ipc.invoke('steam_overlay_open_chat', { steamId: steamId }); },
+        openProfile:      function(steamId)  { return ipc.invoke('steam_overlay_open_profile', { steamId: steamId }); },
         openStore:        function()         { return ipc.invoke('steam_activate_overlay_to_store'); },
-        unlockAchievement:       function(id)    { return ipc.invoke('steam_unlock_achievement', { achievementId: id }); },
+        activateToStore:  function(appId, flag) { return ipc.invoke('steam_activate_to_store', { appId: appId, flag: flag || 'none' }); },
+        isOverlayEnabled: function()         { return ipc.invoke('steam_overlay_is_enabled'); },
+        activateInviteDialog: function(lobbyId) { return ipc.invoke('steam_activate_invite_dialog', { lobbyId: lobbyId }); },
+        getFriendRichPresence: function(steamId, key) { return ipc.invoke('steam_get_friend_rich_presence', { steamId: steamId, key: key }); },
+        getFriendGamePlayed:   function(steamId)      { return ipc.invoke('steam_get_friend_game_played', { steamId: steamId }); },
+        getFriendNickname:     function(steamId)      { return ipc.invoke('steam_get_friend_nickname', { steamId: steamId }); },
+        getFriendCount:        function(flags)        { return ipc.invoke('steam_get_friend_count', { flags: flags }); },
+        getAvatars:            function(steamIds)     { return ipc.invoke('steam_get_avatars', { steamIds: steamIds }); },
+        getLaunchConnect:      function()             { return ipc.invoke('steam_get_launch_connect'); },
+        getLaunchCommandLine:  function()             { return ipc.invoke('steam_get_launch_command_line'); },
+        getLaunchConnectLobby: function()             { return ipc.invoke('steam_get_launch_connect_lobby'); },
+        setConnectString:      function(value)        { return ipc.invoke('steam_set_connect_string', { value: value == null ? null : value }); },
+        unlockAchievement:       function(id, suppressNotification) { return ipc.invoke('steam_unlock_achievement', { achievementId: id, suppressNotification: !!suppressNotification }); },
+        storeStats:              function()      { return ipc.invoke('steam_store_stats'); },
         getUnlockedAchievements: function(ids)    { return ipc.invoke('steam_get_unlocked_achievements', { ids: ids }); },
+        getAchievementNames:     function()       { return ipc.invoke('steam_get_achievement_names'); },
+        getAchievementDisplayInfo: function(name) { return ipc.invoke('steam_get_achievement_display_info', { name: name }); },
         getStatI32:              function(name)   { return ipc.invoke('steam_get_stat_i32', { statName: name }); },
         setStat:                 function(name, val){ return ipc.invoke('steam_set_stat', { statName: name, value: val }); },
+        incrementStatInt:        function(name, delta) { return ipc.invoke('steam_increment_stat_int', { name: name, delta: delta }); },
+        incrementStatFloat:      function(name, delta) { return ipc.invoke('steam_increment_stat_float', { name: name, delta: delta }); },
         clearAchievement:        function(id)        { return ipc.invoke('steam_clear_achievement', { achievementId: id }); },
         resetAllStats:           function(achievementsToo) { return ipc.invoke('steam_reset_all_stats', { achievementsToo: !!achievementsToo }); },
+        requestCurrentStats:     function()       { return ipc.invoke('steam_request_current_stats'); },
+        getStatsSnapshot:        function(achievements, intStats, floatStats) {
+            return ipc.invoke('steam_get_stats_snapshot', { achievements: achievements || [], intStats: intStats || [], floatStats: floatStats || [] });
+        },
+        isCloudFilePersisted:    function(fileName) { return ipc.invoke('steam_cloud_file_persisted', { fileName: fileName }); },
+        getCloudSyncPlatforms:   function(fileName) { return ipc.invoke('steam_cloud_get_sync_platforms', { fileName: fileName }); },
+        getSteamInstallPath:     function()       { return ipc.invoke('steam_get_steam_install_path'); },
+        getLibraryFolders:       function()       { return ipc.invoke('steam_get_library_folders'); },
+        listActionSets:          function()       { return ipc.invoke('steam_input_list_action_sets'); },
+        getCloudSetting:         function(key)    { return ipc.invoke('steam_settings_get', { key: key }); },
+        setCloudSetting:         function(key, value) { return ipc.invoke('steam_settings_set', { key: key, value: String(value) }); },
+        initRelayAccess:         function()       { return ipc.invoke('steam_net_init_relay_access'); },
+        getRelayStatus:          function()       { return ipc.invoke('steam_net_get_relay_status'); },
     };
     // Shift+Tab: prevent browser focus cycling and open overlay manually.
     // On macOS, Steam cannot inject into WKWebView's Metal surface, so we
     // must trigger it ourselves rather than relying on Steam's global hook.
     // F12: blocked so Steam can use it for screenshots. F8 opens devtools instead.
-    window.addEventListener('keydown', function(e) {
+    // The previous handler is removed first so a dev reload does not stack them.
+    if (window.__steamBridgeKeydown) {
+        window.removeEventListener('keydown', window.__steamBridgeKeydown, true);
+    }
+    window.__steamBridgeKeydown = function(e) {
         if (e.shiftKey && e.key === 'Tab') {
             e.preventDefault();
             window.steam.activateOverlay('Friends');
@@ -268,15 +1245,77 @@ const STEAM_INIT_SCRIPT: &str = r#"
         if (e.key === 'F8' && localStorage.getItem('dicy_gfx_fps') === 'on') {
             ipc.invoke('open_devtools').catch(function() {});
         }
-    }, true);
+    };
+    window.addEventListener('keydown', window.__steamBridgeKeydown, true);
 })();
 "#;
 
+/// Webviews that get `window.steam`.
+#[cfg(not(target_os = "android"))]
+const STEAM_BRIDGE_WINDOWS: &[&str] = &["main"];
+
+/// Init scripts run in every webview, so the Steam bridge filters itself by
+/// label to keep `window.steam` out of auxiliary windows. In debug builds a
+/// `steam_bridge.js` next to the executable replaces the embedded script, so
+/// the bridge can be edited without rebuilding.
+#[cfg(not(target_os = "android"))]
+fn steam_init_script(labels: &[&str]) -> String {
+    let dev_override = cfg!(debug_assertions)
+        .then(|| std::env::current_exe().map(|exe| exe.with_file_name("steam_bridge.js")))
+        .and_then(|path| path.and_then(std::fs::read_to_string).ok());
+    let source = dev_override.unwrap_or_else(|| STEAM_INIT_SCRIPT.to_string());
+    source.replace("__STEAM_BRIDGE_LABELS__", &serde_json::json!(labels).to_string())
+}
+
+/// Dev only: re-read the bridge script and evaluate it in the calling webview.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_reload_bridge_script(window: tauri::WebviewWindow) -> Result<(), String> {
+    if !cfg!(debug_assertions) {
+        return Err("Bridge reload is only available in dev builds".to_string());
+    }
+    window.eval(&steam_init_script(STEAM_BRIDGE_WINDOWS)).map_err(|e| e.to_string())
+}
+
+/// Whether Steam is usable and who is signed in, as seen by `webview`. The
+/// identity is only included for `STEAM_BRIDGE_WINDOWS`, like `window.steam`.
+#[cfg(not(target_os = "android"))]
+fn steam_ready_payload(webview: &tauri::Webview) -> Result<serde_json::Value, String> {
+    let bridged = STEAM_BRIDGE_WINDOWS.contains(&webview.label());
+    let state = webview.state::<SteamState>();
+    let guard = state.lock().map_err(|e| e.to_string())?;
+    Ok(match guard.as_ref() {
+        Some(s) if bridged => serde_json::json!({ "available": true, "userName": s.user_name, "steamId": s.steam_id }),
+        available => serde_json::json!({ "available": available.is_some(), "userName": null, "steamId": null }),
+    })
+}
+
+/// Emit `steam://ready` to the webview that just loaded. Sent on every page
+/// load because events emitted during `setup` fire before the webview is
+/// listening. The event is not buffered, so a listener attached
+/// asynchronously can still miss it; read `steam_get_ready_state` as well.
+#[cfg(not(target_os = "android"))]
+fn emit_steam_ready(webview: &tauri::Webview) {
+    use tauri::Emitter;
+    let Ok(payload) = steam_ready_payload(webview) else { return };
+    let _ = webview.emit_to(tauri::EventTarget::webview(webview.label()), "steam://ready", payload);
+}
+
+/// The `steam://ready` payload on demand, for listeners that subscribed late.
+#[cfg(not(target_os = "android"))]
+#[tauri::command]
+fn steam_get_ready_state(webview: tauri::Webview) -> Result<serde_json::Value, String> {
+    steam_ready_payload(&webview)
+}
+
 const COMMON_INIT_SCRIPT: &str = r#"
 (function() {
     var ipc = window.__TAURI_INTERNALS__;
     if (!ipc) return;
     window.openUrl = function(url) { return ipc.invoke('open_url', { url: url }); };
+    // Not on window.steam: that bridge is only injected when Steam is available.
+    window.getLaunchSource = function() { return ipc.invoke('steam_get_launch_source'); };
+    window.getSteamReadyState = function() { return ipc.invoke('steam_get_ready_state'); };
 })();
 "#;
 
@@ -315,6 +1354,21 @@ pub fn run() {
                 let app_id    = client.utils().app_id().0;
                 eprintln!("[Steam] Initialized OK: {} (ID: {}), AppId: {}", user_name, steam_id, app_id);
 
+                // Registered before the pump starts so the UserStatsReceived
+                // Steam sends right after init is not missed.
+                let stats_received: StatsOutcome = Arc::new(Mutex::new(None));
+                let cb_stats = client.register_callback({
+                    let stats_received = stats_received.clone();
+                    move |c: steamworks::UserStatsReceived| {
+                        if c.steam_id.raw() != steam_id {
+                            return;
+                        }
+                        if let Ok(mut outcome) = stats_received.lock() {
+                            *outcome = Some(c.result.map_err(|e| e.to_string()));
+                        }
+                    }
+                });
+
                 // Pump Steam callbacks on a background thread so the overlay
                 // can communicate, render, and respond to Shift+Tab.
                 let cb_client = client.clone();
@@ -325,12 +1379,19 @@ pub fn run() {
                     }
                 });
 
+                let args: Vec<String> = std::env::args().collect();
+                let launch_arg = parse_connect_arg(args.iter().map(String::as_str));
+
                 let app = SteamApp {
                     client,
                     user_name,
                     steam_id,
                     app_id,
-                    _remote_play_cbs: Vec::new(),
+                    started_at: Instant::now(),
+                    launch_connect: launch_arg.map(|(_, value)| value.to_string()),
+                    launch_lobby: launch_arg.and_then(connect_lobby_id),
+                    stats_received,
+                    _callbacks: vec![cb_stats],
                 };
                 (Some(app), true)
             }
@@ -348,17 +1409,59 @@ pub fn run() {
                 steam_get_user_name,
                 steam_get_steam_id,
                 steam_get_app_id,
+                steam_get_game_language,
+                steam_get_steam_ui_language,
+                steam_pick_best_language,
+                steam_get_session_seconds,
+                steam_is_steam_deck,
+                steam_is_app_installed,
+                steam_is_logged_on,
+                steam_remote_play_get_session_count,
+                steam_dismiss_gamepad_text_input,
+                steam_get_launch_source,
+                steam_get_ready_state,
                 steam_is_dev,
+                steam_reload_bridge_script,
                 steam_quit,
                 steam_activate_overlay,
+                steam_overlay_open_chat,
+                steam_overlay_open_profile,
                 steam_activate_overlay_to_store,
+                steam_activate_to_store,
+                steam_overlay_is_enabled,
+                steam_activate_invite_dialog,
+                steam_get_friend_rich_presence,
+                steam_get_friend_game_played,
+                steam_get_friend_nickname,
+                steam_get_friend_count,
+                steam_get_avatars,
+                steam_get_launch_connect,
+                steam_get_launch_command_line,
+                steam_get_launch_connect_lobby,
+                steam_set_connect_string,
                 open_devtools,
                 steam_unlock_achievement,
+                steam_store_stats,
                 steam_get_unlocked_achievements,
+                steam_get_achievement_names,
+                steam_get_achievement_display_info,
                 steam_get_stat_i32,
                 steam_set_stat,
+                steam_increment_stat_int,
+                steam_increment_stat_float,
                 steam_clear_achievement,
                 steam_reset_all_stats,
+                steam_request_current_stats,
+                steam_get_stats_snapshot,
+                steam_net_init_relay_access,
+                steam_net_get_relay_status,
+                steam_get_steam_install_path,
+                steam_get_library_folders,
+                steam_input_list_action_sets,
+                steam_settings_get,
+                steam_settings_set,
+                steam_cloud_file_persisted,
+                steam_cloud_get_sync_platforms,
                 storage_read_all,
                 storage_write_all,
                 storage_get_path,
@@ -370,7 +1473,7 @@ pub fn run() {
         if steam_available {
             builder = builder.plugin(
                 tauri::plugin::Builder::<tauri::Wry, ()>::new("steam-bridge")
-                    .js_init_script(STEAM_INIT_SCRIPT.to_string())
+                    .js_init_script(steam_init_script(STEAM_BRIDGE_WINDOWS))
                     .build(),
             );
         }
@@ -379,6 +1482,11 @@ pub fn run() {
         builder = builder.plugin(
             tauri::plugin::Builder::<tauri::Wry, ()>::new("common-bridge")
                 .js_init_script(COMMON_INIT_SCRIPT.to_string())
+                .on_page_load(|webview, payload| {
+                    if payload.event() == tauri::webview::PageLoadEvent::Finished {
+                        emit_steam_ready(webview);
+                    }
+                })
                 .build(),
         );
     }
@@ -456,8 +1564,71 @@ pub fn run() {
                                 );
                             }
                         });
-                        sa._remote_play_cbs.push(cb_connected);
-                        sa._remote_play_cbs.push(cb_disconnected);
+                        let cb_join = sa.client.register_callback({
+                            let handle = handle.clone();
+                            move |c: steamworks::GameRichPresenceJoinRequested| {
+                                let _ = handle.emit(
+                                    "steam://join-requested",
+                                    serde_json::json!({
+                                        "connect": c.connect,
+                                        "friendSteamId": c.friend_steam_id.raw(),
+                                    }),
+                                );
+                            }
+                        });
+                        // steamworks 0.12's GameOverlayActivated only carries the
+                        // active flag, not which overlay screen was opened.
+                        let cb_overlay = sa.client.register_callback({
+                            let handle = handle.clone();
+                            move |c: steamworks::GameOverlayActivated| {
+                                let _ = handle.emit("steam://overlay", serde_json::json!({ "active": c.active }));
+                            }
+                        });
+                        let cb_stats_stored = sa.client.register_callback({
+                            let handle = handle.clone();
+                            move |c: steamworks::UserStatsStored| {
+                                let _ = handle.emit(
+                                    "steam://stats-stored",
+                                    serde_json::json!({
+                                        "success": c.result.is_ok(),
+                                        "error": c.result.err().map(|e| e.to_string()),
+                                    }),
+                                );
+                            }
+                        });
+                        let cb_achievement_stored = sa.client.register_callback({
+                            let handle = handle.clone();
+                            move |c: steamworks::UserAchievementStored| {
+                                let _ = handle.emit(
+                                    "steam://achievement-stored",
+                                    serde_json::json!({
+                                        "achievementName": c.achievement_name,
+                                        "currentProgress": c.current_progress,
+                                        "maxProgress": c.max_progress,
+                                    }),
+                                );
+                            }
+                        });
+                        let cb_persona = sa.client.register_callback({
+                            let handle = handle.clone();
+                            move |c: steamworks::PersonaStateChange| {
+                                let _ = handle.emit(
+                                    "steam://persona-changed",
+                                    serde_json::json!({
+                                        "steamId": c.steam_id.raw(),
+                                        "avatar": c.flags.contains(steamworks::PersonaChange::AVATAR),
+                                        "name": c.flags.contains(steamworks::PersonaChange::NAME),
+                                    }),
+                                );
+                            }
+                        });
+                        sa._callbacks.push(cb_connected);
+                        sa._callbacks.push(cb_disconnected);
+                        sa._callbacks.push(cb_join);
+                        sa._callbacks.push(cb_overlay);
+                        sa._callbacks.push(cb_stats_stored);
+                        sa._callbacks.push(cb_achievement_stored);
+                        sa._callbacks.push(cb_persona);
                     }
                 }
             }
@@ -473,3 +1644,121 @@ pub fn run() {
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+
+#[cfg(all(test, not(target_os = "android")))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_connect_arg_finds_connect_and_lobby() {
+        let argv = ["diception.exe", "-silent", "+connect_lobby", "109775241021923456"];
+        let arg = parse_connect_arg(argv);
+        assert_eq!(arg, Some(("+connect_lobby", "109775241021923456")));
+        assert_eq!(arg.and_then(connect_lobby_id), Some(109775241021923456));
+
+        let arg = parse_connect_arg("+connect 10.0.0.5:27015".split_whitespace());
+        assert_eq!(arg, Some(("+connect", "10.0.0.5:27015")));
+        assert_eq!(arg.and_then(connect_lobby_id), None);
+
+        assert_eq!(parse_connect_arg(["+connect_lobby"]), None);
+    }
+
+    #[test]
+    fn pop_code_unpacks_three_and_four_letter_codes() {
+        let pack = |code: &[u8]| {
+            let b = |i: usize| code.get(i).copied().unwrap_or(0) as u32;
+            (b(0) << 16) | (b(1) << 8) | b(2) | (b(3) << 24)
+        };
+        assert_eq!(pop_code(pack(b"fra")), "fra");
+        assert_eq!(pop_code(pack(b"sgp1")), "sgp1");
+    }
+
+    #[test]
+    fn manifest_action_sets_reads_shipped_manifest() {
+        let src = include_str!("../../steam/game_actions_X.vdf");
+        assert_eq!(manifest_action_sets(src), vec!["GameControls"]);
+    }
+
+    #[test]
+    fn manifest_action_sets_skips_comments_and_other_blocks() {
+        let src = r##"
+"In Game Actions"
+{
+	// "actions" { "Commented" { } }
+	"actions"
+	{
+		"GameControls"
+		{
+			"title"		"#Set_Game"
+			"StickPadGyro"
+			{
+				"cursor_move" { "title" "#Action_Move" "input_mode" "absolute_mouse" }
+			}
+		}
+		Menu
+		{
+			"title"		"#Set_Menu"
+		}
+	}
+	"localization"
+	{
+		"english"
+		{
+			"Set_Game"		"Game Controls"
+		}
+	}
+}
+"##;
+        assert_eq!(manifest_action_sets(src), vec!["GameControls", "Menu"]);
+    }
+
+    #[test]
+    fn library_folder_paths_modern_layout() {
+        let src = r#"
+"libraryfolders"
+{
+	"0"
+	{
+		"path"		"C:\\Program Files (x86)\\Steam"
+		"label"		""
+		"contentid"		"4511432486541265354"
+		"totalsize"		"0"
+		"update_clean_bytes_tally"		"5830180096"
+		"time_last_update_corruption"		"0"
+		"apps"
+		{
+			"228980"		"5830180096"
+			"4429000"		"4000"
+		}
+	}
+	"1"
+	{
+		"path"		"D:\\SteamLibrary"
+		"label"		""
+		"apps"
+		{
+			"620"		"12884901888"
+		}
+	}
+}
+"#;
+        assert_eq!(
+            library_folder_paths(src),
+            vec!["C:\\Program Files (x86)\\Steam", "D:\\SteamLibrary"],
+        );
+    }
+
+    #[test]
+    fn library_folder_paths_legacy_layout() {
+        let src = r#"
+"LibraryFolders"
+{
+	"TimeNextStatsReport"		"1561832478"
+	"ContentStatsID"		"-158337411110787451"
+	"1"		"D:\\SteamLibrary"
+	"2"		"/mnt/games/steam"
+}
+"#;
+        assert_eq!(library_folder_paths(src), vec!["D:\\SteamLibrary", "/mnt/games/steam"]);
+    }
+}